@@ -1,15 +1,23 @@
 use log::info;
 use ratatui::prelude::*;
+use ratatui::{backend::TestBackend, buffer::Buffer};
 use simplelog::{Config, LevelFilter, WriteLogger};
 use std::any::Any;
 use std::fs::File;
 use std::io;
 use std::sync::{
-    Arc,
+    Arc, Condvar, Mutex,
     atomic::{AtomicBool, Ordering},
     mpsc,
 };
 use std::thread;
+use std::time::{Duration, Instant};
+#[cfg(feature = "async")]
+use futures::{Stream, StreamExt};
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
 
 impl<T> PartialEq for dyn Subscription<T> {
     fn eq(&self, other: &Self) -> bool {
@@ -17,6 +25,13 @@ impl<T> PartialEq for dyn Subscription<T> {
     }
 }
 
+#[cfg(feature = "async")]
+impl<T> PartialEq for dyn AsyncSubscription<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.equals_a(other)
+    }
+}
+
 pub struct QuitFlag {
     quit: Arc<AtomicBool>,
 }
@@ -51,17 +66,239 @@ pub trait Subscription<T>: DynEq + Send + Sync {
     fn run(&self, sender: mpsc::Sender<T>, alive: QuitFlag);
 }
 
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+    fn sleep(&self, d: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, d: Duration) {
+        thread::sleep(d);
+    }
+}
+
+// A subscription that ticks every `duration`, producing a message from the
+// instant of each tick. Diffing only looks at the duration, so updating the
+// model keeps a running timer alive instead of restarting it every frame.
+pub struct Interval<M> {
+    duration: Duration,
+    factory: Arc<dyn Fn(Instant) -> M + Send + Sync>,
+    clock: Arc<dyn Clock>,
+}
+
+impl<M> Interval<M> {
+    pub fn new<F>(duration: Duration, factory: F) -> Self
+    where
+        F: Fn(Instant) -> M + Send + Sync + 'static,
+    {
+        Self::with_clock(duration, factory, Arc::new(SystemClock))
+    }
+
+    pub fn with_clock<F>(duration: Duration, factory: F, clock: Arc<dyn Clock>) -> Self
+    where
+        F: Fn(Instant) -> M + Send + Sync + 'static,
+    {
+        Self {
+            duration,
+            factory: Arc::new(factory),
+            clock,
+        }
+    }
+}
+
+impl<M> PartialEq for Interval<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.duration == other.duration
+    }
+}
+
+impl<M: Send + 'static> Subscription<M> for Interval<M> {
+    fn run(&self, sender: mpsc::Sender<M>, alive: QuitFlag) {
+        // Sleep in small steps so `alive` is polled between them and
+        // `SubRec::stop` tears the timer down promptly.
+        let step = Duration::from_millis(50);
+        let mut next = self.clock.now() + self.duration;
+        loop {
+            if alive.raised() {
+                break;
+            }
+            let now = self.clock.now();
+            if now >= next {
+                if sender.send((self.factory)(now)).is_err() {
+                    break;
+                }
+                next += self.duration;
+            } else {
+                self.clock.sleep((next - now).min(step));
+            }
+        }
+    }
+}
+
 pub trait Command<T>: Send + Sync {
     fn run(&self, sender: mpsc::Sender<T>);
+    // A flag the loop can raise to abandon an in-flight command when the app
+    // quits. Commands without cancellation return `None`.
+    fn cancel_token(&self) -> Option<QuitFlag> {
+        None
+    }
+}
+
+// An effect driven as a future on the shared async runtime instead of its own
+// OS thread. Only available with the `async` feature.
+#[cfg(feature = "async")]
+pub trait AsyncCommand<T>: Send + Sync {
+    fn run(self: Box<Self>, sender: mpsc::Sender<T>) -> Pin<Box<dyn Future<Output = ()> + Send>>;
+    // A flag the loop can raise to abandon an in-flight command when the app
+    // quits. Commands without cancellation return `None`.
+    fn cancel_token(&self) -> Option<QuitFlag> {
+        None
+    }
 }
+
+// The async analogue of `Subscription`: a long-lived effect modelled as a
+// stream whose items are fed back into the loop until it ends or is stopped.
+#[cfg(feature = "async")]
+pub trait AsyncSubscription<T>: DynEq + Send + Sync {
+    fn run(&self) -> Pin<Box<dyn Stream<Item = T> + Send>>;
+}
+
 pub trait TearApp<T, M> {
     fn init(&self) -> (T, Cmd<M>);
     fn update(&self, model: &mut T, msg: M, quit_program: &QuitFlag) -> Cmd<M>;
     fn subscriptions(&self, model: &T) -> Vec<Box<dyn Subscription<M>>>;
+    #[cfg(feature = "async")]
+    fn async_subscriptions(&self, _model: &T) -> Vec<Box<dyn AsyncSubscription<M>>> {
+        Vec::new()
+    }
     fn view(&self, frame: &mut Frame, model: &T);
 }
 
-pub type Cmd<T> = Option<Box<dyn Command<T>>>;
+// A command to hand to the loop. `Async` runs on the shared runtime; `Sync`
+// spawns an OS thread as before; `None` does nothing.
+pub enum Cmd<T> {
+    None,
+    Sync(Box<dyn Command<T>>),
+    #[cfg(feature = "async")]
+    Async(Box<dyn AsyncCommand<T>>),
+}
+
+// A command that runs a fallible operation and always reports back: the `Ok`
+// message, or — after `retries` further attempts spaced by `backoff` — a
+// message mapped from the final error. The retry loop polls a cancellation
+// flag between attempts so an in-flight retry is abandoned when the app quits;
+// get a handle with `cancel_token` and raise it to cancel.
+pub struct Task<M, E> {
+    op: Box<dyn Fn() -> Result<M, E> + Send + Sync>,
+    on_error: Box<dyn Fn(E) -> M + Send + Sync>,
+    retries: usize,
+    backoff: Duration,
+    cancel: QuitFlag,
+}
+
+impl<M, E> Task<M, E> {
+    pub fn new<O, H>(op: O, on_error: H) -> Self
+    where
+        O: Fn() -> Result<M, E> + Send + Sync + 'static,
+        H: Fn(E) -> M + Send + Sync + 'static,
+    {
+        Self {
+            op: Box::new(op),
+            on_error: Box::new(on_error),
+            retries: 0,
+            backoff: Duration::ZERO,
+            cancel: QuitFlag::new(),
+        }
+    }
+
+    // Retry the operation up to `retries` extra times, waiting `backoff`
+    // between attempts.
+    pub fn retrying(mut self, retries: usize, backoff: Duration) -> Self {
+        self.retries = retries;
+        self.backoff = backoff;
+        self
+    }
+
+    pub fn cancel_token(&self) -> QuitFlag {
+        self.cancel.clone()
+    }
+
+    // Run the attempt/retry loop, returning the message to deliver, or `None`
+    // if cancelled before a message was produced.
+    fn execute(&self) -> Option<M> {
+        let mut attempt = 0;
+        loop {
+            if self.cancel.raised() {
+                return None;
+            }
+            match (self.op)() {
+                Ok(msg) => return Some(msg),
+                Err(err) => {
+                    if attempt >= self.retries {
+                        return Some((self.on_error)(err));
+                    }
+                    attempt += 1;
+                    if !self.sleep_cancellable(self.backoff) {
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+
+    // Sleep `d` in small steps so the cancel flag is polled between them.
+    // Returns `false` if cancellation was observed.
+    fn sleep_cancellable(&self, d: Duration) -> bool {
+        let step = Duration::from_millis(50);
+        let mut remaining = d;
+        while !remaining.is_zero() {
+            if self.cancel.raised() {
+                return false;
+            }
+            let chunk = remaining.min(step);
+            thread::sleep(chunk);
+            remaining -= chunk;
+        }
+        !self.cancel.raised()
+    }
+}
+
+impl<M: Send + Sync + 'static, E: Send + Sync + 'static> Command<M> for Task<M, E> {
+    fn run(&self, sender: mpsc::Sender<M>) {
+        if let Some(msg) = self.execute() {
+            let _ = sender.send(msg);
+        }
+    }
+
+    fn cancel_token(&self) -> Option<QuitFlag> {
+        Some(self.cancel.clone())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<M: Send + Sync + 'static, E: Send + Sync + 'static> AsyncCommand<M> for Task<M, E> {
+    fn run(self: Box<Self>, sender: mpsc::Sender<M>) -> Pin<Box<dyn Future<Output = ()> + Send>> {
+        Box::pin(async move {
+            // Drive the same blocking attempt/retry loop as the sync path off
+            // the runtime's worker threads, so the blocking op and its backoff
+            // waits don't stall the shared executor and cancellation has the
+            // same per-step granularity as `sleep_cancellable`.
+            if let Ok(Some(msg)) = tokio::task::spawn_blocking(move || self.execute()).await {
+                let _ = sender.send(msg);
+            }
+        })
+    }
+
+    fn cancel_token(&self) -> Option<QuitFlag> {
+        Some(self.cancel.clone())
+    }
+}
 
 pub trait DynEq {
     // An &Any can be cast to a reference to a concrete type.
@@ -84,46 +321,172 @@ impl<S: 'static + PartialEq> DynEq for S {
     }
 }
 
-pub struct SubRec<T> {
-    sub: Arc<Box<dyn Subscription<T>>>,
+// Either kind of subscription a `SubRec` can drive. Diffing compares the two
+// only when they are the same kind; a sync and an async sub are never equal.
+enum AnySub<T> {
+    Sync(Arc<Box<dyn Subscription<T>>>),
+    #[cfg(feature = "async")]
+    Async(Arc<Box<dyn AsyncSubscription<T>>>),
+}
+
+impl<T: 'static> AnySub<T> {
+    fn equals(&self, other: &AnySub<T>) -> bool {
+        match (self, other) {
+            (AnySub::Sync(a), AnySub::Sync(b)) => a.equals_a(b),
+            #[cfg(feature = "async")]
+            (AnySub::Async(a), AnySub::Async(b)) => a.equals_a(b),
+            #[cfg(feature = "async")]
+            _ => false,
+        }
+    }
+}
+
+pub(crate) struct SubRec<T> {
+    sub: AnySub<T>,
     halt_flag: QuitFlag,
     thread: Option<thread::JoinHandle<()>>,
 }
 
 impl<T: 'static> PartialEq for SubRec<T> {
     fn eq(&self, other: &Self) -> bool {
-        self.sub.equals_a(&other.sub)
+        self.sub.equals(&other.sub)
     }
 }
 
 impl<T: Send + 'static> SubRec<T> {
-    pub fn new(sub: Box<dyn Subscription<T>>) -> SubRec<T> {
+    pub(crate) fn new(sub: Box<dyn Subscription<T>>) -> SubRec<T> {
+        Self {
+            sub: AnySub::Sync(Arc::new(sub)),
+            halt_flag: QuitFlag::new(),
+            thread: None,
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) fn new_async(sub: Box<dyn AsyncSubscription<T>>) -> SubRec<T> {
         Self {
-            sub: Arc::new(sub),
+            sub: AnySub::Async(Arc::new(sub)),
             halt_flag: QuitFlag::new(),
             thread: None,
         }
     }
 
-    pub fn run(&mut self, sender: mpsc::Sender<T>) {
-        let sub = self.sub.clone();
+    pub(crate) fn run(&mut self, sender: mpsc::Sender<T>, executor: &Executor) {
+        // Only the async backend reaches into the executor; the sync path just
+        // spawns a thread.
+        #[cfg(not(feature = "async"))]
+        let _ = executor;
         let halt_flag = self.halt_flag.clone();
-        self.thread = Some(thread::spawn(move || sub.run(sender, halt_flag)));
+        match &self.sub {
+            AnySub::Sync(sub) => {
+                let sub = sub.clone();
+                self.thread = Some(thread::spawn(move || sub.run(sender, halt_flag)));
+            }
+            #[cfg(feature = "async")]
+            AnySub::Async(sub) => {
+                executor.pump_stream(sub.clone(), sender, halt_flag);
+            }
+        }
     }
 
-    pub fn stop(&mut self) {
+    pub(crate) fn stop(&mut self) {
         self.halt_flag.raise();
     }
 }
 
-fn handle<M>(cmd: Cmd<M>, sender: mpsc::Sender<M>)
+// Spawns effects either on OS threads (the default) or, with the `async`
+// feature, onto a single shared tokio runtime so hundreds of concurrent
+// effects share one executor instead of one thread each.
+struct Executor {
+    #[cfg(feature = "async")]
+    handle: tokio::runtime::Handle,
+}
+
+impl Executor {
+    // Spawn `cmd` and return a flag that is raised once it finishes, so the
+    // loop can drop the command's cancel token instead of keeping it forever.
+    fn spawn_command<M: 'static + Send>(
+        &self,
+        cmd: Box<dyn Command<M>>,
+        sender: mpsc::Sender<M>,
+    ) -> QuitFlag {
+        let done = QuitFlag::new();
+        let signal = done.clone();
+        thread::spawn(move || {
+            cmd.run(sender);
+            signal.raise();
+        });
+        done
+    }
+
+    #[cfg(feature = "async")]
+    fn spawn_async_command<M: 'static + Send>(
+        &self,
+        cmd: Box<dyn AsyncCommand<M>>,
+        sender: mpsc::Sender<M>,
+    ) -> QuitFlag {
+        let done = QuitFlag::new();
+        let signal = done.clone();
+        let fut = cmd.run(sender);
+        self.handle.spawn(async move {
+            fut.await;
+            signal.raise();
+        });
+        done
+    }
+
+    #[cfg(feature = "async")]
+    fn pump_stream<M: 'static + Send>(
+        &self,
+        sub: Arc<Box<dyn AsyncSubscription<M>>>,
+        sender: mpsc::Sender<M>,
+        alive: QuitFlag,
+    ) {
+        self.handle.spawn(async move {
+            let stream = sub.run();
+            futures::pin_mut!(stream);
+            while let Some(item) = stream.next().await {
+                if alive.raised() || sender.send(item).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+// A cancellable in-flight command: `cancel` abandons it, `done` is raised once
+// it finishes so the loop can forget it.
+struct InFlight {
+    cancel: QuitFlag,
+    done: QuitFlag,
+}
+
+// Spawn `cmd`. When it is cancellable, first drop any finished entries from
+// `cancels`, then register its cancellation flag so the loop can abandon it on
+// quit without the vector growing unbounded as tasks complete.
+fn handle<M>(cmd: Cmd<M>, sender: mpsc::Sender<M>, executor: &Executor, cancels: &mut Vec<InFlight>)
 where
     M: 'static + Send + Sync,
 {
-    if let Some(c) = cmd {
-        thread::spawn(move || {
-            c.run(sender);
-        });
+    match cmd {
+        Cmd::None => {}
+        Cmd::Sync(c) => {
+            let cancel = c.cancel_token();
+            let done = executor.spawn_command(c, sender);
+            if let Some(cancel) = cancel {
+                cancels.retain(|f| !f.done.raised());
+                cancels.push(InFlight { cancel, done });
+            }
+        }
+        #[cfg(feature = "async")]
+        Cmd::Async(c) => {
+            let cancel = c.cancel_token();
+            let done = executor.spawn_async_command(c, sender);
+            if let Some(cancel) = cancel {
+                cancels.retain(|f| !f.done.raised());
+                cancels.push(InFlight { cancel, done });
+            }
+        }
     }
 }
 
@@ -140,30 +503,49 @@ where
 
     let mut terminal = ratatui::init();
 
+    // Keep one runtime for the whole loop; the executor hands out its handle so
+    // every async effect shares it. With the feature off the executor is a
+    // zero-sized marker that only spawns threads.
+    #[cfg(feature = "async")]
+    let runtime = tokio::runtime::Runtime::new()?;
+    let executor = Executor {
+        #[cfg(feature = "async")]
+        handle: runtime.handle().clone(),
+    };
+
     let (mut model, cmd) = app.init();
     let quit_program = QuitFlag::new();
     let (sender, receiver) = std::sync::mpsc::channel::<M>();
-    let initial_subscriptions = app.subscriptions(&model);
 
-    let subs: Vec<SubRec<M>> = initial_subscriptions.into_iter().map(SubRec::new).collect();
+    let build_subs = |model: &T| -> Vec<SubRec<M>> {
+        let subs: Vec<SubRec<M>> = app.subscriptions(model).into_iter().map(SubRec::new).collect();
+        #[cfg(feature = "async")]
+        let subs = {
+            let mut subs = subs;
+            subs.extend(app.async_subscriptions(model).into_iter().map(SubRec::new_async));
+            subs
+        };
+        subs
+    };
 
-    let mut subs: Vec<SubRec<M>> = subs
+    let mut subs: Vec<SubRec<M>> = build_subs(&model)
         .into_iter()
         .map(|mut sub| {
-            sub.run(sender.clone());
+            sub.run(sender.clone(), &executor);
             sub
         })
         .collect();
 
-    handle(cmd, sender.clone());
+    // Cancellation flags for in-flight commands, tripped when the app quits.
+    let mut cancels: Vec<InFlight> = Vec::new();
+    handle(cmd, sender.clone(), &executor, &mut cancels);
 
     loop {
         info!("Looping");
         terminal.draw(|f| app.view(f, &model))?;
         let msg = receiver.recv().unwrap();
         let cmd = app.update(&mut model, msg, &quit_program);
-        let new_subscriptions = app.subscriptions(&model);
-        let mut new_subs: Vec<SubRec<M>> = new_subscriptions.into_iter().map(SubRec::new).collect();
+        let mut new_subs: Vec<SubRec<M>> = build_subs(&model);
         subs.retain_mut(|sub| {
             let pos = new_subs.iter().position(|new_sub| sub == new_sub);
             if let Some(pos1) = pos {
@@ -175,14 +557,19 @@ where
             }
         });
         new_subs.iter_mut().for_each(|s| {
-            s.run(sender.clone());
+            s.run(sender.clone(), &executor);
         });
         subs.append(&mut new_subs);
         info!("Subscriptions: {:?}", subs.len());
 
-        handle(cmd, sender.clone());
+        handle(cmd, sender.clone(), &executor, &mut cancels);
 
         if quit_program.raised() {
+            // Abandon any in-flight command mid-retry instead of waiting out
+            // its backoff loop.
+            for flight in &cancels {
+                flight.cancel.raise();
+            }
             break;
         }
     }
@@ -190,3 +577,252 @@ where
     ratatui::restore();
     Ok(())
 }
+
+// A `Clock` whose time only moves when the test tells it to. `sleep` blocks on
+// a condvar until `advance` pushes `now` past the requested deadline (or 50ms
+// of real time pass) rather than returning instantly, so an `Interval` parks between ticks instead of
+// busy-spinning and fires exactly when `advance` crosses its next deadline.
+// Clones share the same time.
+pub struct MockClock {
+    inner: Arc<(Mutex<Instant>, Condvar)>,
+}
+
+impl MockClock {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new((Mutex::new(Instant::now()), Condvar::new())),
+        }
+    }
+
+    pub fn advance(&self, d: Duration) {
+        let (lock, cvar) = &*self.inner;
+        let mut now = lock.lock().unwrap();
+        *now += d;
+        cvar.notify_all();
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for MockClock {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        *self.inner.0.lock().unwrap()
+    }
+
+    fn sleep(&self, d: Duration) {
+        let (lock, cvar) = &*self.inner;
+        let now = lock.lock().unwrap();
+        if d.is_zero() {
+            return;
+        }
+        // Park until `advance` crosses the deadline, but wake at least every
+        // 50ms of real time so a caller looping on this (e.g. `Interval`) still
+        // polls its halt flag and honours `SubRec::stop` without needing an
+        // `advance`.
+        let deadline = *now + d;
+        let _ = cvar
+            .wait_timeout_while(now, Duration::from_millis(50), |now| *now < deadline)
+            .unwrap();
+    }
+}
+
+// Drives a `TearApp` without `ratatui::init()` or a real terminal so apps can
+// be unit-tested: feed messages through `update`, inspect the model, render
+// `view` into a `TestBackend` buffer, and run the commands `update` returns.
+pub struct TestHarness<'a, T, M> {
+    app: &'a dyn TearApp<T, M>,
+    model: T,
+    quit: QuitFlag,
+    cmd: Cmd<M>,
+    sender: mpsc::Sender<M>,
+    receiver: mpsc::Receiver<M>,
+    sub_alive: Vec<QuitFlag>,
+}
+
+impl<'a, T, M> TestHarness<'a, T, M>
+where
+    M: 'static + Send + Sync,
+    T: 'static,
+{
+    pub fn new(app: &'a dyn TearApp<T, M>) -> Self {
+        let (model, cmd) = app.init();
+        let (sender, receiver) = mpsc::channel::<M>();
+        Self {
+            app,
+            model,
+            quit: QuitFlag::new(),
+            cmd,
+            sender,
+            receiver,
+            sub_alive: Vec::new(),
+        }
+    }
+
+    // Spawn `sub` on a background thread, delivering its messages into the
+    // harness. Pair an `Interval` built `with_clock(MockClock)` with `advance`
+    // and `step` to drive timer subscriptions deterministically.
+    pub fn run_subscription(&mut self, sub: Box<dyn Subscription<M>>) {
+        let alive = QuitFlag::new();
+        self.sub_alive.push(alive.clone());
+        let sender = self.sender.clone();
+        thread::spawn(move || sub.run(sender, alive));
+    }
+
+    // Wait up to `timeout` for a subscription message, then drain any others
+    // already queued, feeding each through `update`. Returns how many were
+    // delivered.
+    pub fn step(&mut self, timeout: Duration) -> usize {
+        let mut n = 0;
+        if let Ok(msg) = self.receiver.recv_timeout(timeout) {
+            self.cmd = self.app.update(&mut self.model, msg, &self.quit);
+            n += 1;
+            while let Ok(msg) = self.receiver.try_recv() {
+                self.cmd = self.app.update(&mut self.model, msg, &self.quit);
+                n += 1;
+            }
+        }
+        n
+    }
+
+    // Feed a message through `update`, keeping the command it returns.
+    pub fn send(&mut self, msg: M) {
+        self.cmd = self.app.update(&mut self.model, msg, &self.quit);
+    }
+
+    pub fn model(&self) -> &T {
+        &self.model
+    }
+
+    // Whether an `update` has raised the quit flag.
+    pub fn quit_raised(&self) -> bool {
+        self.quit.raised()
+    }
+
+    // Draw `view` into an off-screen buffer for assertions.
+    pub fn render(&self, width: u16, height: u16) -> Buffer {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|f| self.app.view(f, &self.model)).unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    // Run the command from the last `update` (or `init`) synchronously on this
+    // thread and return the messages it sent.
+    pub fn run_cmd(&mut self) -> Vec<M> {
+        let (sender, receiver) = mpsc::channel::<M>();
+        match std::mem::replace(&mut self.cmd, Cmd::None) {
+            Cmd::None => {}
+            Cmd::Sync(c) => c.run(sender),
+            #[cfg(feature = "async")]
+            Cmd::Async(c) => {
+                tokio::runtime::Runtime::new().unwrap().block_on(c.run(sender));
+            }
+        }
+        receiver.try_iter().collect()
+    }
+}
+
+impl<T, M> Drop for TestHarness<'_, T, M> {
+    fn drop(&mut self) {
+        // Tear down any subscriptions still running in the background.
+        for alive in &self.sub_alive {
+            alive.raise();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn task_reports_failure_after_exhausting_retries() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let seen = calls.clone();
+        let task = Task::new(
+            move || {
+                seen.fetch_add(1, Ordering::SeqCst);
+                Err::<i32, &str>("boom")
+            },
+            |_err| 99,
+        )
+        .retrying(2, Duration::ZERO);
+
+        assert_eq!(task.execute(), Some(99));
+        // One initial attempt plus two retries.
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn task_cancel_abandons_in_flight_backoff() {
+        let task = Task::new(|| Err::<i32, &str>("boom"), |_err| 7)
+            .retrying(100, Duration::from_secs(30));
+        let cancel = task.cancel_token();
+
+        let (sender, receiver) = mpsc::channel();
+        let handle = thread::spawn(move || task.run(sender));
+
+        thread::sleep(Duration::from_millis(80));
+        cancel.raise();
+
+        // The cancelled task delivers no message and unwinds promptly instead
+        // of sleeping out its 30s backoff.
+        assert!(receiver.recv_timeout(Duration::from_secs(2)).is_err());
+        handle.join().unwrap();
+    }
+
+    struct TickApp;
+
+    impl TearApp<u32, ()> for TickApp {
+        fn init(&self) -> (u32, Cmd<()>) {
+            (0, Cmd::None)
+        }
+
+        fn update(&self, model: &mut u32, _msg: (), _quit: &QuitFlag) -> Cmd<()> {
+            *model += 1;
+            Cmd::None
+        }
+
+        fn subscriptions(&self, _model: &u32) -> Vec<Box<dyn Subscription<()>>> {
+            Vec::new()
+        }
+
+        fn view(&self, _frame: &mut Frame, _model: &u32) {}
+    }
+
+    #[test]
+    fn interval_fires_under_mock_clock() {
+        let app = TickApp;
+        let mut harness = TestHarness::new(&app);
+        let clock = MockClock::new();
+        let interval =
+            Interval::with_clock(Duration::from_millis(100), |_| (), Arc::new(clock.clone()));
+        harness.run_subscription(Box::new(interval));
+
+        // Push virtual time forward a tick at a time until the subscription
+        // delivers, independent of when the spawned thread reads the clock.
+        let mut ticks = 0;
+        for _ in 0..20 {
+            clock.advance(Duration::from_millis(100));
+            ticks += harness.step(Duration::from_millis(200));
+            if ticks >= 1 {
+                break;
+            }
+        }
+
+        assert!(ticks >= 1);
+        assert!(*harness.model() >= 1);
+    }
+}